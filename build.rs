@@ -0,0 +1,37 @@
+//! Regenerates `keywords_client.h` and the Go client's copy of it from `src/lib.rs`, using the
+//! `cbindgen` command line tool when it is installed. Without it the committed headers are left
+//! as they are.
+
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+const HEADERS: [&str; 2] = ["keywords_client.h", "clients/go/koko_keywords/libkoko.h"];
+
+fn main() {
+    println!("cargo:rerun-if-changed=src/lib.rs");
+    println!("cargo:rerun-if-changed=cbindgen.toml");
+
+    let output = match Command::new("cbindgen").args(["--config", "cbindgen.toml"]).output() {
+        Ok(output) => output,
+        Err(_) => return,
+    };
+    if !output.status.success() {
+        println!(
+            "cargo:warning=cbindgen failed, C headers not regenerated: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+        return;
+    }
+
+    for header in HEADERS {
+        let path = Path::new(header);
+        // Only rewrite a header when it changed, so its timestamp does not trigger rebuilds.
+        if fs::read(path).ok().as_deref() == Some(&output.stdout[..]) {
+            continue;
+        }
+        if let Err(e) = fs::write(path, &output.stdout) {
+            println!("cargo:warning=could not write {}: {}", header, e);
+        }
+    }
+}
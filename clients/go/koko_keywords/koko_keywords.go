@@ -8,6 +8,7 @@ import "C"
 
 import (
   "errors"
+  "fmt"
 )
 
 func Match(query string, filter string, version string) (bool, error) {
@@ -36,5 +37,9 @@ func Match(query string, filter string, version string) (bool, error) {
     return true, nil
   }
 
+  if match_value < 0 {
+    return false, fmt.Errorf("Keyword matching failed with error code %d. Please contact us at api.kokocares.org if this issue persists.", match_value)
+  }
+
   return false, nil
 }
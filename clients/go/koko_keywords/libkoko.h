@@ -1 +1,159 @@
-int c_koko_keywords_match(const char *input, const char *filter, const char *version);
+#ifndef KEYWORDS_CLIENT_H
+#define KEYWORDS_CLIENT_H
+
+/* Generated by cbindgen from src/lib.rs, do not edit. */
+
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+
+typedef struct KeywordMatcher KeywordMatcher;
+
+// Counters of how the cache has been used since the matcher was created. `#[repr(C)]` so it can
+// be filled in by `c_koko_keywords_stats`.
+typedef struct CacheStats {
+  // Matches served from a live cache entry.
+  uint64_t hits;
+  // Matches that found no live entry, whether they refreshed it, served it stale or were
+  // backing off from a failed refresh.
+  uint64_t misses;
+  // Successful cache refreshes.
+  uint64_t refreshes;
+  // Failed cache refreshes.
+  uint64_t refresh_failures;
+  // Entries evicted from memory, see `KokoConfig::max_cache_entries`.
+  uint64_t evictions;
+  // Filter/version entries currently cached, expired or not.
+  uint64_t entries;
+  // Times the circuit breaker opened, see `KokoConfig::circuit_breaker_threshold`.
+  uint64_t circuit_opens;
+  // Whether the circuit breaker is open, fetches failing with `CircuitOpen`.
+  bool circuit_open;
+} CacheStats;
+
+// Registers the patterns in `patterns`, a JSON array of strings, as local keywords for `filter`.
+// Returns 0 on success, `InvalidInput` (-9) if the array is malformed, or another negative
+// error code, e.g. `InvalidRegex` (-8) when one of the patterns does not compile.
+intptr_t c_koko_keywords_add_local(const char *filter, const char *patterns);
+
+// Same as `c_koko_keywords_add_local`, but registers exclusions: an input matching one of them
+// is never reported as a match.
+intptr_t c_koko_keywords_add_local_exclusions(const char *filter, const char *patterns);
+
+// Returns the filters in the global matcher's cache as a newly allocated JSON array, e.g.
+// `[{"filter":"profanity","version":"2","expires_at":1700000000}]`, or null on failure. The
+// string must be released with `c_koko_keywords_free_string`.
+char *c_koko_keywords_cached_filters_json(void);
+
+// Drops all of the global matcher's cached keywords, so the next match of each filter reloads
+// them. Returns 0 on success or a negative error code, e.g. the init error if the matcher could
+// not be initialized.
+intptr_t c_koko_keywords_clear_cache(void);
+
+// Drops the global matcher's cached keywords for the filter, so the next match reloads them.
+// Returns 0 on success or a negative error code.
+intptr_t c_koko_keywords_expire(const char *filter, const char *version);
+
+// Releases a string returned by `c_koko_keywords_last_error`, `c_koko_keywords_stats_json`,
+// `c_koko_keywords_cached_filters_json` or `c_koko_keywords_last_request_id`. Passing null is a
+// no-op.
+void c_koko_keywords_free_string(char *string);
+
+// Returns 0 if the keywords API can be reached, or the negative error code a refresh would fail
+// with, e.g. `InvalidCredentials` (-2) or `NetworkError` (-18). Meant for readiness probes. See
+// `KokoClient::health_check`.
+intptr_t c_koko_keywords_health(void);
+
+// Returns 0 once the global matcher is configured, or a negative error code. See
+// `koko_keywords_init`.
+intptr_t c_koko_keywords_init(void);
+
+// Returns a newly allocated, NUL-terminated description of the most recent error an FFI call
+// returned on this thread, or null if there was none. The message is owned by the caller and
+// stays valid until it is released with `c_koko_keywords_free_string`; later calls do not touch
+// it.
+char *c_koko_keywords_last_error(void);
+
+// Returns the `X-Request-Id` of the global matcher's most recent failed refresh as a newly
+// allocated string, or null if no refresh failed. The string must be released with
+// `c_koko_keywords_free_string`.
+char *c_koko_keywords_last_request_id(void);
+
+// Returns 1 if the input matches the filter's keywords, 0 if not, or a negative `KokoError`
+// code. Failures to load the keywords are told apart by code: `InvalidCredentials` (-2) for a
+// 401, `Forbidden` (-15), `RateLimited` (-16), `ServerError` (-17) for a 5xx, `NetworkError`
+// (-18) when the API cannot be reached, `TlsError` (-19) and `Timeout` (-11).
+intptr_t c_koko_keywords_match(const char *input, const char *filter, const char *version);
+
+// Matches `count` inputs against the same filter, writing 1, 0 or a negative error code for each
+// input into the matching slot of `results`, which must have room for `count` values. A null,
+// malformed or too long input only fails its own slot. Returns 0 once the results are written,
+// or the error code (also written to every slot) if the keywords could not be loaded. A null
+// `inputs` or `results` with a non-zero `count` fails with `InvalidInput` (-9).
+intptr_t c_koko_keywords_match_batch(const char *const *inputs, size_t count, const char *filter, const char *version, intptr_t *results);
+
+// Same as `c_koko_keywords_match`, but also writes the matched pattern into `out_buf`.
+// `out_buf_len` must hold the buffer capacity and is updated to the length needed for the
+// pattern including its NUL terminator. If the buffer is too small nothing is written and
+// `BufferTooSmall` (-7) is returned. A null `out_buf` behaves exactly like
+// `c_koko_keywords_match`.
+intptr_t c_koko_keywords_match_info(const char *input, const char *filter, const char *version, char *out_buf, size_t *out_buf_len);
+
+// Same as `c_koko_keywords_match`, but returns `NotReady` (-20) instead of waiting for the
+// keywords API when the filter's keywords are not cached, and loads them in the background. See
+// `KokoClient::match_nonblocking`.
+intptr_t c_koko_keywords_match_nonblocking(const char *input, const char *filter, const char *version);
+
+// Same as `c_koko_keywords_match`, but also writes the byte offsets of the match within `input`
+// into `out_start` and `out_end`. Either pointer may be null. The offsets are left untouched when
+// nothing matched.
+intptr_t c_koko_keywords_match_offsets(const char *input, const char *filter, const char *version, size_t *out_start, size_t *out_end);
+
+// Same as `c_koko_keywords_match`, but also writes the microseconds spent matching into
+// `out_match_us` and those spent refreshing the cache into `out_refresh_us`, 0 if the keywords
+// were not fetched, e.g. because they were cached or served stale. Either pointer may be null.
+// Nothing is written on error.
+intptr_t c_koko_keywords_match_timed(const char *input, const char *filter, const char *version, uint64_t *out_match_us, uint64_t *out_refresh_us);
+
+// Releases a matcher from `c_koko_keywords_matcher_from_json`. Passing null is a no-op.
+void c_koko_keywords_matcher_free(KeywordMatcher *matcher);
+
+// Compiles a keywords API response body into a standalone matcher that never makes HTTP
+// requests. Returns null on failure, see `c_koko_keywords_last_error`. The matcher must be
+// released with `c_koko_keywords_matcher_free`.
+KeywordMatcher *c_koko_keywords_matcher_from_json(const char *json);
+
+// Same as `c_koko_keywords_match`, but matches against a matcher from
+// `c_koko_keywords_matcher_from_json`.
+intptr_t c_koko_keywords_matcher_match(const KeywordMatcher *matcher, const char *input);
+
+// Loads the global matcher's keywords for the filter unless they are already cached, so that
+// the first match does not wait for them. Meant to be called at startup to fail fast, e.g. on
+// `InvalidCredentials` (-2). Returns 0 on success or a negative error code.
+intptr_t c_koko_keywords_preload(const char *filter, const char *version);
+
+// Refreshes the global matcher's keywords for the filter, ignoring their expiry. Returns 0 on
+// success or a negative error code. See `KokoClient::force_refresh`.
+intptr_t c_koko_keywords_refresh(const char *filter, const char *version);
+
+// Caches `json`, a keywords API response body, as the global matcher's keywords for the filter
+// for `ttl_secs` seconds, without making any HTTP requests. Returns 0 on success, `ParseError`
+// (-4) if the body does not parse, or another negative error code, e.g. `InvalidRegex` (-8) when
+// one of the patterns does not compile.
+intptr_t c_koko_keywords_seed(const char *filter, const char *version, const char *json, uint64_t ttl_secs);
+
+// Stops the global matcher's background refresher, e.g. before unloading the library. Returns 0,
+// or a negative error code.
+intptr_t c_koko_keywords_shutdown(void);
+
+// Fills `out` with the global matcher's `CacheStats`. Returns 0 on success, `InvalidInput` (-9)
+// if `out` is null, or another negative error code.
+intptr_t c_koko_keywords_stats(CacheStats *out);
+
+// Returns the global matcher's `CacheStats` as a newly allocated JSON object, e.g.
+// `{"hits":10,"misses":1,"refreshes":1,"refresh_failures":0,"evictions":0,"entries":1,
+// "circuit_opens":0,"circuit_open":false}`, or null on failure. The string must be released with
+// `c_koko_keywords_free_string`.
+char *c_koko_keywords_stats_json(void);
+
+#endif /* KEYWORDS_CLIENT_H */
@@ -48,5 +48,7 @@ def match(text, filters="", version=None):
       raise RuntimeError("Unable to parse response from API. Please contact us at api.kokocares.org if this issue persists.")
   elif match_value == -5:
       raise RuntimeError("Invalid url. Please ensure the url used is valid.")
+  elif match_value < 0:
+      raise RuntimeError(f"Keyword matching failed with error code {match_value}. Please contact us at api.kokocares.org if this issue persists.")
 
   return bool(match_value)
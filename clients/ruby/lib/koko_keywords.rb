@@ -45,6 +45,8 @@ module KokoKeywords
       raise RuntimeError.new("Unable to parse response from API. Please contact us at api.kokocares.org if this issue persists.")
     elsif match_value == -5
       raise RuntimeError.new("Invalid url. Please ensure the url used is valid.")
+    elsif match_value < 0
+      raise RuntimeError.new("Keyword matching failed with error code #{match_value}. Please contact us at api.kokocares.org if this issue persists.")
     end
 
     !match_value.zero?